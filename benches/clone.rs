@@ -0,0 +1,66 @@
+//! Benchmarks comparing the cost of cloning an `Error` in its two shapes:
+//! a bare `ErrorKind`-only error (the `Repr::Simple` fast path) and a
+//! fully-populated error carrying a class, message, details and a source.
+//!
+//! Before the `Arc`-backed `Repr` redesign, every clone deep-copied the
+//! `class`/`message` `String`s and the `details` `BTreeMap`; afterwards,
+//! cloning a fully-populated error is a single atomic refcount bump, and
+//! cloning a bare-kind error touches no heap allocation at all.
+//!
+//! Run with `cargo bench --bench clone` (requires `criterion` as a
+//! dev-dependency).
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use oxiderr::{Error, ErrorKind};
+
+const KIND: ErrorKind = ErrorKind("NotFound", "MSG001", 404, "Not Found");
+
+fn bare_kind_error() -> Error {
+    Error::from(KIND)
+}
+
+fn fully_populated_error() -> Error {
+    let mut details = std::collections::BTreeMap::new();
+    details.insert(
+        "request_id".to_string(),
+        serde_value::to_value("01JABCDEF").unwrap(),
+    );
+    Error::from(KIND)
+        .set_class("Client::NotFound::UserNotFound".to_string())
+        .set_message("No user with that id".to_string())
+        .set_details(Some(details))
+        .with_source(Error::from_raw_os_error(2))
+}
+
+fn clone_bare_kind(c: &mut Criterion) {
+    let error = bare_kind_error();
+    c.bench_function("clone bare-kind Error", |b| {
+        b.iter(|| black_box(error.clone()))
+    });
+}
+
+fn clone_fully_populated(c: &mut Criterion) {
+    let error = fully_populated_error();
+    c.bench_function("clone fully-populated Error", |b| {
+        b.iter(|| black_box(error.clone()))
+    });
+}
+
+fn propagate_through_result(c: &mut Criterion) {
+    let error = fully_populated_error();
+
+    fn propagate(err: Error) -> Result<(), Error> {
+        Err(err)
+    }
+
+    c.bench_function("propagate fully-populated Error through Result", |b| {
+        b.iter(|| black_box(propagate(error.clone())))
+    });
+}
+
+criterion_group!(
+    benches,
+    clone_bare_kind,
+    clone_fully_populated,
+    propagate_through_result
+);
+criterion_main!(benches);
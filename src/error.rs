@@ -1,5 +1,6 @@
 use crate::ErrorKind;
 use serde::Serialize;
+use std::sync::Arc;
 
 /// Trait representing a structured error with categorized information.
 ///
@@ -36,36 +37,426 @@ pub trait AsError {
     fn details(&self) -> Option<std::collections::BTreeMap<String, serde_value::Value>>;
 }
 
+/// Returns the default `Side::Kind::Error` class string derived from a kind.
+///
+/// Used whenever a `Full` representation hasn't been materialized yet, so a
+/// bare-kind `Error` still reports a sensible `class()`.
+fn default_class(kind: &ErrorKind) -> String {
+    format!("{}::{}::Error", kind.side(), kind.name())
+}
+
+/// The heap-allocated, fully-populated half of an [`Error`].
+///
+/// Kept behind an `Arc` in [`Repr::Full`] so that cloning an `Error` which
+/// carries a custom class, message, details, source or payload is a single
+/// atomic refcount bump instead of a deep copy of every field.
+#[derive(Debug, Clone)]
+struct Full {
+    kind: ErrorKind,
+    class: String,
+    message: String,
+    details: Option<std::collections::BTreeMap<String, serde_value::Value>>,
+    source: Option<Box<Error>>,
+    payload: Option<Arc<dyn std::error::Error + Send + Sync + 'static>>,
+}
+
+/// The internal representation of an [`Error`].
+///
+/// `Simple` holds nothing but an [`ErrorKind`] (itself three
+/// `&'static str`s and a `u16`), so building and cloning a "just a kind"
+/// error costs no heap allocation at all — the same trick
+/// `std::io::Error`'s packed `Repr` uses for its common case. `Full` is
+/// used as soon as a custom class, message, details, source or payload is
+/// attached.
+#[derive(Debug, Clone)]
+enum Repr {
+    Simple(ErrorKind),
+    Full(Arc<Full>),
+}
+
 /// A structured error type with categorized information.
 ///
 /// The `Error` struct represents an error with a specific kind, classification,
 /// message, and optional additional details.
 ///
 /// This structure is designed to facilitate error handling by providing
-/// detailed information that can be logged or displayed.
+/// detailed information that can be logged or displayed. Internally it is a
+/// thin wrapper around a [`Repr`], which keeps `Clone` cheap (the
+/// [`ErrorKind`] of a bare-kind error is stored inline, so an `Error` is
+/// several words wide rather than pointer-sized):
+/// see [`Error::kind`], [`Error::class`], [`Error::message`] and
+/// [`Error::details`] for the accessors that replace the old public fields.
+///
+/// # Breaking change
+///
+/// Prior to this cheap-clone `Repr`, `kind`, `class`, `message` and `details`
+/// were public fields that could be read or assigned directly (e.g.
+/// `err.message = "...".to_string()`, `err.details.unwrap_or_default()`).
+/// They are private now, so any such direct field access or struct-literal
+/// construction of `Error` no longer compiles. This is a semver-breaking
+/// release; bump the crate's major/minor version and call it out in the
+/// changelog accordingly. Callers need to migrate to the equivalent methods:
+///
+/// | before                    | after                                  |
+/// |---------------------------|-----------------------------------------|
+/// | `err.kind`                | [`Error::kind`]`()`                     |
+/// | `err.class`               | [`Error::class`]`()`                    |
+/// | `err.message`             | [`Error::message`]`()`                  |
+/// | `err.details`              | [`Error::details`]`()`                  |
+/// | `err.message = v`         | `err = err.`[`set_message`](Error::set_message)`(v)` |
+/// | `err.details = v`         | `err = err.`[`set_details`](Error::set_details)`(v)` |
+/// | `Error { kind, .. }`      | [`Error::default`]`()` plus `set_*` calls (fields are private, no struct literal) |
 ///
 #[derive(Debug, Clone, Serialize)]
+#[serde(into = "SerError")]
 pub struct Error {
-    /// The kind of error.
+    repr: Repr,
+}
+
+/// A plain, serializable snapshot of an [`Error`]'s `class`, `message` and
+/// `details`, matching the shape this crate has always serialized (`kind`
+/// and `source` are never part of the JSON payload).
+#[derive(Serialize)]
+struct SerError {
+    class: String,
+    message: String,
+    details: Option<std::collections::BTreeMap<String, serde_value::Value>>,
+}
+
+impl From<Error> for SerError {
+    fn from(error: Error) -> Self {
+        SerError {
+            class: error.class(),
+            message: error.message(),
+            details: error.details(),
+        }
+    }
+}
+
+impl Error {
+    /// Returns the `Full` representation, if this error has one.
+    fn full(&self) -> Option<&Full> {
+        match &self.repr {
+            Repr::Full(full) => Some(full),
+            Repr::Simple(_) => None,
+        }
+    }
+
+    /// Consumes this error, materializing (and owning) its `Full`
+    /// representation.
     ///
-    /// This field categorizes the error, allowing distinct handling based on
-    /// its type. It is skipped during serialization.
-    #[serde(skip_serializing)]
-    pub kind: ErrorKind,
+    /// If the `Full` is still shared with another cloned `Error`, it is
+    /// deep-copied here; otherwise this is a plain unwrap with no
+    /// allocation. A bare `Simple` error is promoted by deriving its class
+    /// and message from the kind, the same way a freshly-built `Full` error
+    /// would.
+    fn into_full(self) -> Full {
+        match self.repr {
+            Repr::Full(full) => Arc::try_unwrap(full).unwrap_or_else(|shared| (*shared).clone()),
+            Repr::Simple(kind) => Full {
+                class: default_class(&kind),
+                message: kind.description().to_string(),
+                kind,
+                details: None,
+                source: None,
+                payload: None,
+            },
+        }
+    }
 
-    /// The class or category of the error.
+    /// The kind of this error.
     ///
-    /// This helps further classify the error beyond its kind.
-    pub class: String,
+    /// # Example
+    /// ```rust
+    /// let error: oxiderr::Error = Default::default();
+    /// assert_eq!(error.kind().name(), "InternalServerError");
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match &self.repr {
+            Repr::Simple(kind) => kind.clone(),
+            Repr::Full(full) => full.kind.clone(),
+        }
+    }
 
-    /// A human-readable message describing the error.
-    pub message: String,
+    /// The class or category of this error.
+    ///
+    /// # Example
+    /// ```rust
+    /// let error: oxiderr::Error = Default::default();
+    /// assert_eq!(error.class(), "Server::InternalServerError::Error");
+    /// ```
+    pub fn class(&self) -> String {
+        match self.full() {
+            Some(full) => full.class.clone(),
+            None => default_class(&self.kind()),
+        }
+    }
 
-    /// Additional details related to the error.
+    /// A human-readable message describing this error.
+    ///
+    /// # Example
+    /// ```rust
+    /// let error: oxiderr::Error = Default::default();
+    /// assert_eq!(error.message(), "Internal Server Error");
+    /// ```
+    pub fn message(&self) -> String {
+        match self.full() {
+            Some(full) => full.message.clone(),
+            None => self.kind().description().to_string(),
+        }
+    }
+
+    /// Additional details related to this error.
+    ///
+    /// # Example
+    /// ```rust
+    /// let error: oxiderr::Error = Default::default();
+    /// assert!(error.details().is_none());
+    /// ```
+    pub fn details(&self) -> Option<std::collections::BTreeMap<String, serde_value::Value>> {
+        self.full().and_then(|full| full.details.clone())
+    }
+
+    /// Returns a reference to the error that caused this one, if any.
+    pub fn source_error(&self) -> Option<&Error> {
+        self.full().and_then(|full| full.source.as_deref())
+    }
+
+    /// Sets the error kind, returning `self` for chaining.
+    pub fn set_kind(self, kind: ErrorKind) -> Self {
+        let mut full = self.into_full();
+        full.kind = kind;
+        Error {
+            repr: Repr::Full(Arc::new(full)),
+        }
+    }
+
+    /// Sets the class, returning `self` for chaining.
+    pub fn set_class(self, class: String) -> Self {
+        let mut full = self.into_full();
+        full.class = class;
+        Error {
+            repr: Repr::Full(Arc::new(full)),
+        }
+    }
+
+    /// Sets the message, returning `self` for chaining.
+    pub fn set_message(self, message: String) -> Self {
+        let mut full = self.into_full();
+        full.message = message;
+        Error {
+            repr: Repr::Full(Arc::new(full)),
+        }
+    }
+
+    /// Sets the details, returning `self` for chaining. Pass `None` to clear
+    /// them.
+    pub fn set_details(
+        self,
+        details: Option<std::collections::BTreeMap<String, serde_value::Value>>,
+    ) -> Self {
+        let mut full = self.into_full();
+        full.details = details;
+        Error {
+            repr: Repr::Full(Arc::new(full)),
+        }
+    }
+
+    /// Attaches a causing error, returning `self` for chaining.
+    ///
+    /// This mirrors `std::io::Error`'s ability to wrap an underlying cause
+    /// instead of flattening it into `details`, so the original error is
+    /// preserved and can be walked later with [`Error::chain`].
+    ///
+    /// # Example
+    /// ```rust
+    /// let cause = oxiderr::Error::default();
+    /// let error = oxiderr::Error::default().with_source(cause);
+    /// assert!(error.source_error().is_some());
+    /// ```
+    pub fn with_source(self, err: Error) -> Self {
+        let mut full = self.into_full();
+        full.source = Some(Box::new(err));
+        Error {
+            repr: Repr::Full(Arc::new(full)),
+        }
+    }
+
+    /// Returns an iterator over this error and all of its causes, starting
+    /// with `self` and following [`Error::source`] until the chain ends.
+    ///
+    /// This lets callers log the full cause stack instead of only the
+    /// outermost, flattened message.
+    ///
+    /// # Example
+    /// ```rust
+    /// let cause = oxiderr::Error::default();
+    /// let error = oxiderr::Error::default().with_source(cause);
+    /// assert_eq!(error.chain().count(), 2);
+    /// ```
+    pub fn chain(&self) -> ErrorChain<'_> {
+        ErrorChain {
+            current: Some(self),
+        }
+    }
+
+    /// Builds an `Error` of the given `kind`, wrapping an arbitrary typed
+    /// `payload`.
     ///
-    /// This optional field contains extra context in a key-value format,
-    /// which can be useful for debugging or logging purposes.
-    pub details: Option<std::collections::BTreeMap<String, serde_value::Value>>,
+    /// This mirrors `std::io::Error::new(kind, error)`: the payload is kept
+    /// around as a type-erased, `Arc`-backed `dyn std::error::Error + Send +
+    /// Sync` (a single allocation, in keeping with [`Error`]'s cheap-clone
+    /// `Repr`) and can be recovered later with [`Error::get_ref`],
+    /// [`Error::get_mut`] or [`Error::into_inner`]. The payload's `Display`
+    /// output seeds `message`, and `class` follows the `Side::Kind::Error`
+    /// convention used throughout this crate.
+    ///
+    /// # Example
+    /// ```rust
+    /// #[derive(Debug)]
+    /// struct Oops(&'static str);
+    /// impl std::fmt::Display for Oops {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///         write!(f, "{}", self.0)
+    ///     }
+    /// }
+    /// impl std::error::Error for Oops {}
+    ///
+    /// let kind = oxiderr::ErrorKind("Oops", "MSG001", 500, "Oops");
+    /// let mut error = oxiderr::Error::new(kind, Oops("oops"));
+    /// assert_eq!(error.message(), "oops");
+    /// assert_eq!(
+    ///     error.get_ref().and_then(|p| p.downcast_ref::<Oops>()).map(|o| o.0),
+    ///     Some("oops")
+    /// );
+    ///
+    /// error.get_mut().and_then(|p| p.downcast_mut::<Oops>()).unwrap().0 = "reboxed";
+    /// let payload = error.into_inner().unwrap();
+    /// assert_eq!(payload.downcast_ref::<Oops>().unwrap().0, "reboxed");
+    /// ```
+    pub fn new<E>(kind: ErrorKind, payload: E) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        let message = payload.to_string();
+        let payload: Arc<dyn std::error::Error + Send + Sync> = Arc::new(payload);
+        Error {
+            repr: Repr::Full(Arc::new(Full {
+                class: default_class(&kind),
+                message,
+                kind,
+                details: None,
+                source: None,
+                payload: Some(payload),
+            })),
+        }
+    }
+
+    /// Returns a shared reference to the typed payload, if one is attached.
+    ///
+    /// Downcast the result against a concrete type with
+    /// `std::error::Error::downcast_ref`, the same way `std::io::Error::get_ref`
+    /// is used.
+    pub fn get_ref(&self) -> Option<&(dyn std::error::Error + Send + Sync + 'static)> {
+        self.full()?.payload.as_deref()
+    }
+
+    /// Returns a mutable reference to the typed payload, if one is attached
+    /// and this `Error` is its unique owner.
+    ///
+    /// Like `Arc::get_mut`, this returns `None` if either the `Error` itself
+    /// or its payload is shared with another cloned `Error`.
+    pub fn get_mut(&mut self) -> Option<&mut (dyn std::error::Error + Send + Sync + 'static)> {
+        match &mut self.repr {
+            Repr::Full(full) => Arc::get_mut(full)?.payload.as_mut().and_then(Arc::get_mut),
+            Repr::Simple(_) => None,
+        }
+    }
+
+    /// Consumes this `Error`, returning the typed payload, if one is
+    /// attached and this `Error` is its unique owner.
+    ///
+    /// Returns `None` if there is no payload, or if the payload is shared
+    /// with another cloned `Error` (mirrors `Arc::try_unwrap`'s failure
+    /// case). The payload comes back as the same `Arc` it is stored in
+    /// rather than a `Box`: a type-erased `dyn Trait` can only be moved out
+    /// of a uniquely-held `Arc` by copying it into a fresh allocation, which
+    /// would spend the exact allocation this series' `Repr` exists to avoid,
+    /// so `into_inner` just hands back the (now sole) handle instead.
+    /// Downcasting works the same way as on a `Box`.
+    pub fn into_inner(self) -> Option<Arc<dyn std::error::Error + Send + Sync + 'static>> {
+        match self.repr {
+            Repr::Full(full) => {
+                let full = Arc::try_unwrap(full).ok()?;
+                let payload = full.payload?;
+                (Arc::strong_count(&payload) == 1).then_some(payload)
+            }
+            Repr::Simple(_) => None,
+        }
+    }
+
+    /// Builds an `Error` directly from a raw OS error code (errno).
+    ///
+    /// The errno is translated into an oxiderr [`ErrorKind`] through the
+    /// same table used by [`From<std::io::Error>`] (see
+    /// [`kind_from_io_error_kind`]), and stashed in `details` under the
+    /// `"os_error"` key so it can be read back with [`Error::raw_os_error`].
+    /// This makes oxiderr usable at FFI boundaries where a syscall just
+    /// returned `-1`.
+    ///
+    /// # Example
+    /// ```rust
+    /// let error = oxiderr::Error::from_raw_os_error(2);
+    /// assert_eq!(error.kind().name(), "NotFound");
+    /// assert_eq!(error.raw_os_error(), Some(2));
+    /// ```
+    pub fn from_raw_os_error(code: i32) -> Self {
+        let io_err = std::io::Error::from_raw_os_error(code);
+        let kind = kind_from_io_error_kind(io_err.kind());
+        let mut details = std::collections::BTreeMap::new();
+        details.insert("os_error".to_string(), serde_value::to_value(code).unwrap());
+        Error {
+            repr: Repr::Full(Arc::new(Full {
+                class: default_class(&kind),
+                message: io_err.to_string(),
+                kind,
+                details: Some(details),
+                source: None,
+                payload: None,
+            })),
+        }
+    }
+
+    /// Builds an `Error` from the OS error that the calling thread last set
+    /// (`errno` on Unix, `GetLastError` on Windows).
+    ///
+    /// Delegates to [`Error::from_raw_os_error`] after reading the current
+    /// errno via `std::io::Error::last_os_error`.
+    pub fn last_os_error() -> Self {
+        let code = std::io::Error::last_os_error()
+            .raw_os_error()
+            .unwrap_or(0);
+        Self::from_raw_os_error(code)
+    }
+
+    /// Returns the raw OS error code attached to this `Error`, if any.
+    ///
+    /// Reads back the `"os_error"` detail populated by
+    /// [`Error::from_raw_os_error`], [`Error::last_os_error`] or the
+    /// [`From<std::io::Error>`] conversion.
+    ///
+    /// # Example
+    /// ```rust
+    /// let error = oxiderr::Error::from_raw_os_error(13);
+    /// assert_eq!(error.raw_os_error(), Some(13));
+    /// assert_eq!(oxiderr::Error::default().raw_os_error(), None);
+    /// ```
+    pub fn raw_os_error(&self) -> Option<i32> {
+        match self.full()?.details.as_ref()?.get("os_error")? {
+            serde_value::Value::I32(code) => Some(*code),
+            _ => None,
+        }
+    }
 }
 
 /// Converts any type implementing `AsError` into an `Error` instance.
@@ -123,10 +514,14 @@ pub struct Error {
 impl<E: AsError> From<E> for Error {
     fn from(value: E) -> Self {
         Error {
-            kind: E::kind(),
-            class: value.class(),
-            message: value.message(),
-            details: value.details(),
+            repr: Repr::Full(Arc::new(Full {
+                kind: E::kind(),
+                class: value.class(),
+                message: value.message(),
+                details: value.details(),
+                source: None,
+                payload: None,
+            })),
         }
     }
 }
@@ -139,12 +534,10 @@ impl<E: AsError> From<E> for Error {
 ///
 /// # Example
 /// ```rust
-/// let custom_error = oxiderr::Error {
-///     kind: oxiderr::ErrorKind("NotFound", "MSG001", 404, "Not Found"),
-///     class: "Client::NotFound::MyError".to_string(),
-///     message: "Not Found".to_string(),
-///     details: None,
-/// };
+/// let custom_error = oxiderr::Error::default()
+///     .set_kind(oxiderr::ErrorKind("NotFound", "MSG001", 404, "Not Found"))
+///     .set_class("Client::NotFound::MyError".to_string())
+///     .set_message("Not Found".to_string());
 /// let io_error: std::io::Error = custom_error.into();
 /// ```
 impl From<Error> for std::io::Error {
@@ -153,6 +546,105 @@ impl From<Error> for std::io::Error {
     }
 }
 
+/// Maps a [`std::io::ErrorKind`] to the oxiderr [`ErrorKind`] it corresponds to.
+///
+/// Shared by [`From<std::io::Error>`] and [`Error::from_raw_os_error`], so the
+/// errno-to-kind classification only lives in one place.
+pub(crate) fn kind_from_io_error_kind(kind: std::io::ErrorKind) -> ErrorKind {
+    use std::io::ErrorKind::*;
+    match kind {
+        NotFound => ErrorKind("NotFound", "IO-00001", 404, "Not Found"),
+        PermissionDenied => ErrorKind("PermissionDenied", "IO-00002", 403, "Permission Denied"),
+        ConnectionRefused => {
+            ErrorKind("ConnectionRefused", "IO-00003", 502, "Connection Refused")
+        }
+        ConnectionReset => ErrorKind("ConnectionReset", "IO-00004", 502, "Connection Reset"),
+        ConnectionAborted => ErrorKind("ConnectionAborted", "IO-00005", 502, "Connection Aborted"),
+        NotConnected => ErrorKind("NotConnected", "IO-00006", 502, "Not Connected"),
+        AddrInUse => ErrorKind("AddrInUse", "IO-00007", 409, "Address In Use"),
+        AddrNotAvailable => {
+            ErrorKind("AddrNotAvailable", "IO-00008", 400, "Address Not Available")
+        }
+        BrokenPipe => ErrorKind("BrokenPipe", "IO-00009", 502, "Broken Pipe"),
+        AlreadyExists => ErrorKind("AlreadyExists", "IO-00010", 409, "Already Exists"),
+        WouldBlock => ErrorKind("WouldBlock", "IO-00011", 408, "Would Block"),
+        InvalidInput => ErrorKind("InvalidInput", "IO-00012", 400, "Invalid Input"),
+        InvalidData => ErrorKind("InvalidData", "IO-00013", 400, "Invalid Data"),
+        TimedOut => ErrorKind("TimedOut", "IO-00014", 408, "Timed Out"),
+        WriteZero => ErrorKind("WriteZero", "IO-00015", 500, "Write Zero"),
+        Interrupted => ErrorKind("Interrupted", "IO-00016", 500, "Interrupted"),
+        UnexpectedEof => ErrorKind("UnexpectedEof", "IO-00017", 500, "Unexpected End Of File"),
+        OutOfMemory => ErrorKind("OutOfMemory", "IO-00018", 500, "Out Of Memory"),
+        _ => ErrorKind("UnknownError", "IO-00000", 500, "Unknown IO Error"),
+    }
+}
+
+/// Converts a [`std::io::Error`] into an `Error`.
+///
+/// The [`std::io::ErrorKind`] is mapped to a matching oxiderr [`ErrorKind`]
+/// (see [`kind_from_io_error_kind`]), and the raw OS error code, if any, is
+/// preserved in `details` under the `"os_error"` key so it survives the
+/// conversion. This lets callers use `?` directly on a failing I/O call in
+/// a function returning `oxiderr::Result`, instead of hand-building an
+/// `Error` on every call site.
+///
+/// # Example
+/// ```rust
+/// use std::fs::File;
+///
+/// fn try_open_file(path: &str) -> oxiderr::Result<File> {
+///     Ok(File::open(path)?)
+/// }
+///
+/// match try_open_file("does-not-exist.txt") {
+///     Ok(_) => unreachable!(),
+///     Err(e) => assert_eq!(e.kind().name(), "NotFound"),
+/// }
+/// ```
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        let kind = kind_from_io_error_kind(err.kind());
+        let mut details = std::collections::BTreeMap::new();
+        if let Some(code) = err.raw_os_error() {
+            details.insert("os_error".to_string(), serde_value::to_value(code).unwrap());
+        }
+        details.insert(
+            "message".to_string(),
+            serde_value::to_value(err.to_string()).unwrap(),
+        );
+        Error {
+            repr: Repr::Full(Arc::new(Full {
+                class: default_class(&kind),
+                message: err.to_string(),
+                kind,
+                details: Some(details),
+                source: None,
+                payload: None,
+            })),
+        }
+    }
+}
+
+/// Converts an [`ErrorKind`] directly into an `Error`.
+///
+/// Mirrors `std::io::Error`'s `From<io::ErrorKind>` impl: this is the
+/// cheapest possible `Error` to build, since it stores nothing but the
+/// kind itself (see [`Repr::Simple`]) and allocates nothing.
+///
+/// # Example
+/// ```rust
+/// let kind = oxiderr::ErrorKind("NotFound", "MSG001", 404, "Not Found");
+/// let error: oxiderr::Error = kind.clone().into();
+/// assert_eq!(error.kind(), kind);
+/// ```
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Self {
+        Error {
+            repr: Repr::Simple(kind),
+        }
+    }
+}
+
 /// Implements the `Display` trait for `Error`.
 ///
 /// This implementation formats the error as a human-readable string,
@@ -166,30 +658,59 @@ impl From<Error> for std::io::Error {
 ///
 /// # Example
 /// ```rust
-/// let error = oxiderr::Error {
-///     kind: oxiderr::ErrorKind("NotFound", "MSG001", 404, "Not Found"),
-///     class: "Client::NotFound::MyError".to_string(),
-///     message: "Not Found".to_string(),
-///     details: None,
-/// };
+/// let error = oxiderr::Error::default()
+///     .set_kind(oxiderr::ErrorKind("NotFound", "MSG001", 404, "Not Found"))
+///     .set_class("Client::NotFound::MyError".to_string())
+///     .set_message("Not Found".to_string());
 /// println!("{}", error);
 /// ```
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = self.kind();
         write!(
             f,
             "{}",
             format!(
                 "[{}] {} ({}) - {}",
-                self.kind.message_id(),
-                self.class,
-                self.kind.code(),
-                self.message
+                kind.message_id(),
+                self.class(),
+                kind.code(),
+                self.message()
             )
         )
     }
 }
 
+/// Implements `std::error::Error` for `Error`.
+///
+/// This makes `Error` interoperate with the rest of the error-handling
+/// ecosystem (e.g. `anyhow`, `Box<dyn std::error::Error>`) and exposes the
+/// wrapped cause, if any, through [`std::error::Error::source`].
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source_error()
+            .map(|err| err as &(dyn std::error::Error + 'static))
+    }
+}
+
+/// An iterator over an [`Error`] and its chain of causing errors.
+///
+/// Created by [`Error::chain`].
+#[derive(Debug, Clone)]
+pub struct ErrorChain<'a> {
+    current: Option<&'a Error>,
+}
+
+impl<'a> Iterator for ErrorChain<'a> {
+    type Item = &'a Error;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let error = self.current.take()?;
+        self.current = error.source_error();
+        Some(error)
+    }
+}
+
 impl Default for Error {
     /// Creates a default instance of `Error`.
     ///
@@ -199,23 +720,73 @@ impl Default for Error {
     /// - **Class**: Describes the error as a server-side internal error (`Server::InternalServerError::Error`).
     /// - **Message**: The human-readable error message ("Internal Server Error").
     /// - **Details**: No additional error details are provided (`None`).
+    /// - **Source**: No causing error is attached (`None`).
     ///
     /// This can be used when you need a generic error with standard values.
+    /// It is built through [`Repr::Simple`], so it allocates nothing.
     ///
     /// # Example
     /// ```
     /// let error: oxiderr::Error = Default::default();
-    /// assert_eq!(error.kind.name(), "InternalServerError");
-    /// assert_eq!(error.message, "Internal Server Error");
-    /// assert_eq!(error.class, "Server::InternalServerError::Error");
-    /// assert!(error.details.is_none());
+    /// assert_eq!(error.kind().name(), "InternalServerError");
+    /// assert_eq!(error.message(), "Internal Server Error");
+    /// assert_eq!(error.class(), "Server::InternalServerError::Error");
+    /// assert!(error.details().is_none());
     /// ```
     fn default() -> Self {
         Error {
-            kind: ErrorKind("InternalServerError", "MSG000", 500, "Internal Server Error"),
-            class: "Server::InternalServerError::Error".to_string(),
-            message: "Internal Server Error".to_string(),
-            details: None,
+            repr: Repr::Simple(ErrorKind(
+                "InternalServerError",
+                "MSG000",
+                500,
+                "Internal Server Error",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_kind_from_io_error_kind() {
+        let cases = [
+            (std::io::ErrorKind::NotFound, "NotFound", 404, "Client"),
+            (
+                std::io::ErrorKind::PermissionDenied,
+                "PermissionDenied",
+                403,
+                "Client",
+            ),
+            (
+                std::io::ErrorKind::ConnectionRefused,
+                "ConnectionRefused",
+                502,
+                "Server",
+            ),
+            (
+                std::io::ErrorKind::AlreadyExists,
+                "AlreadyExists",
+                409,
+                "Client",
+            ),
+            (std::io::ErrorKind::TimedOut, "TimedOut", 408, "Client"),
+            (std::io::ErrorKind::WriteZero, "WriteZero", 500, "Server"),
+            (
+                std::io::ErrorKind::UnexpectedEof,
+                "UnexpectedEof",
+                500,
+                "Server",
+            ),
+            (std::io::ErrorKind::Other, "UnknownError", 500, "Server"),
+        ];
+
+        for (io_kind, name, code, side) in cases {
+            let kind = kind_from_io_error_kind(io_kind);
+            assert_eq!(kind.name(), name, "{io_kind:?} -> name");
+            assert_eq!(kind.code(), code, "{io_kind:?} -> code");
+            assert_eq!(kind.side(), side, "{io_kind:?} -> side");
         }
     }
 }
@@ -42,6 +42,7 @@
 //!     class: String,
 //!     message: String,
 //!     details: Option<std::collections::BTreeMap<String, serde_value::Value>>,
+//!     source: Option<Box<oxiderr::Error>>,
 //! }
 //!
 //! impl NotFoundError {
@@ -52,6 +53,7 @@
 //!             class: format!("{}::{}::{}", Self::kind.side(), Self::kind.name(), "NotFoundError"),
 //!             message: Self::kind.description().into(),
 //!             details: None,
+//!             source: None,
 //!         }
 //!     }
 //!
@@ -65,16 +67,15 @@
 //!         self
 //!     }
 //!
+//!     /// Converts an `oxiderr::Error` into a `NotFoundError`, keeping the
+//!     /// original error reachable through `std::error::Error::source`
+//!     /// (and its own `chain()`) instead of flattening it into `details`.
 //!     pub fn convert(error: oxiderr::Error) -> Self {
-//!         let mut err_clone = error.clone();
-//!         let mut details = error.details.unwrap_or_default();
-//!         err_clone.details = None;
-//!         details.insert("origin".to_string(), serde_value::to_value(err_clone).unwrap());
-//!
 //!         Self {
 //!             class: format!("{}::{}::{}", Self::kind.side(), Self::kind.name(), "NotFoundError"),
-//!             message: Self::kind.description().into(),
-//!             details: Some(details),
+//!             message: error.message(),
+//!             details: error.details(),
+//!             source: Some(Box::new(error)),
 //!         }
 //!     }
 //! }
@@ -94,13 +95,30 @@
 //!     }
 //! }
 //!
-//! impl std::error::Error for NotFoundError {}
+//! impl std::error::Error for NotFoundError {
+//!     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+//!         self.source.as_deref().map(|e| e as &(dyn std::error::Error + 'static))
+//!     }
+//! }
 //!
 //! impl std::fmt::Display for NotFoundError {
 //!     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
 //!         write!(f, "[{}] {} ({}): {}", Self::kind.message_id(), "NotFoundError", Self::kind.code(), self.message())
 //!     }
 //! }
+//!
+//! let root = oxiderr::Error::default().set_message("disk unavailable".to_string());
+//! let origin = oxiderr::Error::default().set_message("read failed".to_string()).with_source(root);
+//! let wrapped = NotFoundError::convert(origin.clone());
+//!
+//! // The origin error survives `convert` with its concrete type intact, so
+//! // callers can downcast it back and walk its full cause chain instead of
+//! // re-parsing a JSON blob out of `details`.
+//! let cause = std::error::Error::source(&wrapped)
+//!     .and_then(|e| e.downcast_ref::<oxiderr::Error>())
+//!     .expect("origin error preserved as a typed source");
+//! assert_eq!(cause.chain().count(), 2);
+//! assert_eq!(cause.message(), origin.message());
 //! ```
 //!
 //! In this example:
@@ -115,11 +133,7 @@
 //! use std::io::Read;
 //!
 //! fn try_open_file(path: &str) -> oxiderr::Result<File> {
-//!     Ok(File::open(path).map_err(|err| {
-//!         let mut err = oxiderr::Error::default();
-//!         err.message = err.to_string();
-//!         err
-//!     })?)
+//!     Ok(File::open(path)?)
 //! }
 //!
 //! fn main() {
@@ -131,10 +145,11 @@
 //!     }
 //! }
 //! ```
-//! This will output:
+//! `oxiderr::Error` implements `From<std::io::Error>`, so the `?` operator
+//! converts the failing `File::open` directly. This will output:
 //!
 //! ```text
-//! [Err-00001] Client::IoError::NotFoundError (500) - No such file or directory (os error 2)
+//! [IO-00001] Client::NotFound::Error (404) - No such file or directory (os error 2)
 //! ```
 //!
 //! # Macros